@@ -0,0 +1,211 @@
+extern crate rand;
+
+use std::cmp::Ordering;
+use std::io::{BufRead, Write};
+use rand::Rng;
+
+pub const MAX_GUESSES: u32 = 5;
+
+pub enum GameResult {
+    Won,
+    Lost,
+    Quit,
+}
+
+pub enum Input {
+    Number(i32),
+    Quit,
+}
+
+/// Compares a guess against the secret number, returning the ordering
+/// alongside the feedback message `play` prints for it.
+pub fn compare_guess(guess: i32, secret_number: i32) -> (Ordering, &'static str) {
+    match guess.cmp(&secret_number) {
+        Ordering::Less => (Ordering::Less, "Too small!"),
+        Ordering::Greater => (Ordering::Greater, "Too big!"),
+        Ordering::Equal => (Ordering::Equal, "You win!"),
+    }
+}
+
+pub fn read_input<R: BufRead, W: Write>(input: &mut R, output: &mut W) -> Input {
+    let mut line = String::new();
+    loop {
+        line.clear();
+        input.read_line(&mut line).expect("Failed to read line");
+        let trimmed = line.trim();
+        if trimmed.eq_ignore_ascii_case("quit") || trimmed.eq_ignore_ascii_case("exit") {
+            return Input::Quit;
+        }
+        match trimmed.parse() {
+            Ok(n) => return Input::Number(n),
+            Err(_) => writeln!(output, "Please input a number, or \"quit\" to leave.").unwrap(),
+        };
+    }
+}
+
+pub fn read_guess<R: BufRead, W: Write>(input: &mut R, output: &mut W, min: i32, max: i32) -> Input {
+    loop {
+        match read_input(input, output) {
+            Input::Quit => return Input::Quit,
+            Input::Number(guess) => {
+                if guess < min {
+                    writeln!(output, "Please input a number that is at least {}.", min).unwrap();
+                } else if guess > max {
+                    writeln!(output, "Please input a number that is no larger than {}.", max).unwrap();
+                } else {
+                    return Input::Number(guess);
+                }
+            }
+        }
+    }
+}
+
+/// Runs the guessing loop against injectable I/O and RNG, making the
+/// otherwise stdin-bound game logic exercisable with scripted input.
+pub fn play<R: BufRead, W: Write, Rg: Rng>(
+    input: &mut R,
+    output: &mut W,
+    rng: &mut Rg,
+    min: i32,
+    max: i32,
+) -> GameResult {
+    let secret_number = rng.gen_range(min, max + 1);
+    let mut tries_left = MAX_GUESSES;
+    loop {
+        writeln!(output, "Guess the number ({} tries left):", tries_left).unwrap();
+        writeln!(output, "Please input your guess, between {} and {}.", min, max).unwrap();
+        let guess = match read_guess(input, output, min, max) {
+            Input::Quit => {
+                writeln!(output, "Goodbye! The number was {}.", secret_number).unwrap();
+                return GameResult::Quit;
+            }
+            Input::Number(guess) => guess,
+        };
+        let (ordering, message) = compare_guess(guess, secret_number);
+        writeln!(output, "{}", message).unwrap();
+        if ordering == Ordering::Equal {
+            return GameResult::Won;
+        }
+        tries_left -= 1;
+        if tries_left == 0 {
+            writeln!(output, "Out of tries! The number was {}.", secret_number).unwrap();
+            return GameResult::Lost;
+        }
+    }
+}
+
+/// Plays against itself via binary search over `[min, max]`. Worst case this
+/// takes `floor(log2(max - min + 1)) + 1` guesses, the optimal number of
+/// comparisons for bisecting a range of that size (not `ceil(log2(range))`,
+/// which undercounts by one whenever the range size is a power of two).
+pub fn auto_play<W: Write>(output: &mut W, min: i32, max: i32, secret_number: i32) {
+    // Widen to i64 so `lo`/`hi`/`mid` arithmetic can't overflow i32 bounds
+    // for wide ranges (e.g. min = i32::MIN, max = i32::MAX).
+    let mut lo = min as i64;
+    let mut hi = max as i64;
+    let mut guesses = 0;
+    loop {
+        let mid = lo + (hi - lo) / 2;
+        guesses += 1;
+        writeln!(output, "Guessing {}...", mid).unwrap();
+        let (ordering, message) = compare_guess(mid as i32, secret_number);
+        writeln!(output, "{}", message).unwrap();
+        match ordering {
+            Ordering::Less => lo = mid + 1,
+            Ordering::Greater => hi = mid - 1,
+            Ordering::Equal => {
+                writeln!(output, "Found {} in {} guesses.", secret_number, guesses).unwrap();
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::mock::StepRng;
+
+    #[test]
+    fn auto_play_converges_on_a_very_wide_range_without_overflow() {
+        let mut output = Vec::new();
+        auto_play(&mut output, i32::MIN, i32::MAX, 2_073_741_824);
+        let printed = String::from_utf8(output).unwrap();
+        assert!(printed.contains("Found 2073741824 in"));
+    }
+
+    #[test]
+    fn compare_guess_reports_too_small() {
+        let (ordering, message) = compare_guess(3, 7);
+        assert_eq!(ordering, Ordering::Less);
+        assert_eq!(message, "Too small!");
+    }
+
+    #[test]
+    fn compare_guess_reports_too_big() {
+        let (ordering, message) = compare_guess(9, 7);
+        assert_eq!(ordering, Ordering::Greater);
+        assert_eq!(message, "Too big!");
+    }
+
+    #[test]
+    fn compare_guess_reports_win() {
+        let (ordering, message) = compare_guess(7, 7);
+        assert_eq!(ordering, Ordering::Equal);
+        assert_eq!(message, "You win!");
+    }
+
+    #[test]
+    fn play_wins_against_scripted_guesses() {
+        // StepRng always returns 0, so the "secret" after gen_range is `min`.
+        let mut rng = StepRng::new(0, 0);
+        let mut input = "1\n".as_bytes();
+        let mut output = Vec::new();
+
+        let result = play(&mut input, &mut output, &mut rng, 1, 10);
+
+        assert!(matches!(result, GameResult::Won));
+        let printed = String::from_utf8(output).unwrap();
+        assert!(printed.contains("You win!"));
+    }
+
+    #[test]
+    fn play_quits_on_exit_command() {
+        let mut rng = StepRng::new(0, 0);
+        let mut input = "exit\n".as_bytes();
+        let mut output = Vec::new();
+
+        let result = play(&mut input, &mut output, &mut rng, 1, 10);
+
+        assert!(matches!(result, GameResult::Quit));
+        let printed = String::from_utf8(output).unwrap();
+        assert!(printed.contains("Goodbye!"));
+    }
+
+    #[test]
+    fn play_reprompts_with_feedback_on_garbage_input() {
+        let mut rng = StepRng::new(0, 0);
+        let mut input = "not a number\n1\n".as_bytes();
+        let mut output = Vec::new();
+
+        let result = play(&mut input, &mut output, &mut rng, 1, 10);
+
+        assert!(matches!(result, GameResult::Won));
+        let printed = String::from_utf8(output).unwrap();
+        assert!(printed.contains("Please input a number, or \"quit\" to leave."));
+    }
+
+    #[test]
+    fn play_loses_after_max_guesses() {
+        let mut rng = StepRng::new(0, 0);
+        // Secret ends up being `min` (0); guess 9 every time to exhaust tries.
+        let mut input = "9\n9\n9\n9\n9\n".as_bytes();
+        let mut output = Vec::new();
+
+        let result = play(&mut input, &mut output, &mut rng, 0, 9);
+
+        assert!(matches!(result, GameResult::Lost));
+        let printed = String::from_utf8(output).unwrap();
+        assert!(printed.contains("Out of tries!"));
+    }
+}
@@ -1,48 +1,100 @@
 extern crate rand;
+extern crate guessing_game;
 
+use std::env;
 use std::io;
-use std::cmp::Ordering;
-use rand::Rng;
-
-fn read_i32() -> i32 {
-    let mut input = String::new();
-    loop {
-        input.clear();
-        io::stdin().read_line(&mut input).expect("Failed to read line");
-        match input.trim().parse() {
-            Ok(n) => return n,
-            Err(_) => println!("Please input a number."),
-        };
-    }
+use rand::{FromEntropy, SeedableRng};
+use rand::rngs::StdRng;
+use guessing_game::{auto_play, play};
+
+const DEFAULT_MIN: i32 = 1;
+const DEFAULT_MAX: i32 = 10;
+
+struct Args {
+    min: i32,
+    max: i32,
+    seed: Option<u64>,
+    auto: bool,
 }
 
-fn read_guess(min: i32, max: i32) -> i32 {
-    loop {
-        let guess = read_i32();
-        if guess < min {
-            println!("Please input a number that is at least {}.", min);
-        } else if guess > max {
-            println!("Please input a number that is no larger than {}.", max);
-        } else {
-            return guess;
+fn parse_args() -> Args {
+    let mut min = DEFAULT_MIN;
+    let mut max = DEFAULT_MAX;
+    let mut seed = None;
+    let mut auto = false;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--min" => {
+                if let Some(value) = args.next() {
+                    match value.parse() {
+                        Ok(parsed) => min = parsed,
+                        Err(_) => println!("Ignoring invalid --min value {:?}; using default {}.", value, DEFAULT_MIN),
+                    }
+                }
+            }
+            "--max" => {
+                if let Some(value) = args.next() {
+                    match value.parse() {
+                        Ok(parsed) => max = parsed,
+                        Err(_) => println!("Ignoring invalid --max value {:?}; using default {}.", value, DEFAULT_MAX),
+                    }
+                }
+            }
+            "--seed" => {
+                if let Some(value) = args.next() {
+                    match value.parse() {
+                        Ok(parsed) => seed = Some(parsed),
+                        Err(_) => println!("Ignoring invalid --seed value {:?}; using a random seed.", value),
+                    }
+                }
+            }
+            "--auto" => auto = true,
+            _ => {}
         }
     }
+
+    if max == i32::MAX {
+        // gen_range's exclusive upper bound is `max + 1`; back off by one so
+        // that addition can't overflow.
+        max -= 1;
+    }
+
+    if min >= max {
+        println!(
+            "Ignoring invalid range (min must be less than max); using defaults {}-{}.",
+            DEFAULT_MIN, DEFAULT_MAX
+        );
+        min = DEFAULT_MIN;
+        max = DEFAULT_MAX;
+    }
+
+    Args { min, max, seed, auto }
+}
+
+fn make_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
 }
 
 fn main() {
     println!("Guess the number!");
-    let (min, max) = (1, 10);
-    let secret_number = rand::thread_rng().gen_range(min, max + 1);
-    loop {
-        println!("Please input your guess, between {} and {}.", min, max);
-        let guess = read_guess(min, max);
-        match guess.cmp(&secret_number) {
-            Ordering::Less    => println!("Too small!"),
-            Ordering::Greater => println!("Too big!"),
-            Ordering::Equal   => {
-                println!("You win!");
-                break;
-            },
-        };
+    let args = parse_args();
+    let (min, max) = (args.min, args.max);
+    let mut rng = make_rng(args.seed);
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+    let mut output = io::stdout();
+
+    if args.auto {
+        use rand::Rng;
+        let secret_number = rng.gen_range(min, max + 1);
+        auto_play(&mut output, min, max, secret_number);
+        return;
     }
+
+    let _ = play(&mut input, &mut output, &mut rng, min, max);
 }